@@ -1,13 +1,22 @@
 use clap::Parser;
 use rayon::prelude::*;
+use regex_automata::dfa::{dense, Automaton};
+use regex_automata::Input;
 use solana_sdk::signature::{Keypair, Signer};
-use std::sync::atomic::{AtomicU64, Ordering};
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap, VecDeque};
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
 use std::sync::Arc;
 use std::time::Instant;
 use std::fs;
 use serde_json::{json, to_string_pretty};
 use chrono::{DateTime, Utc};
 
+/// Every character that can legally appear in a base58-encoded Solana
+/// address (the base58 alphabet omits 0, O, I, and l to avoid visual
+/// ambiguity).
+const BASE58_ALPHABET: &str = "123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz";
+
 #[derive(Parser, Debug)]
 #[command(author, version, about, long_about = None)]
 struct Args {
@@ -46,6 +55,33 @@ struct Args {
     /// Clear/reset the output file before starting search
     #[arg(long)]
     clear_output: bool,
+
+    /// Path to a file with one target pattern per line. When set, the
+    /// search matches against every pattern in the file at once via a
+    /// shared Aho-Corasick automaton instead of --prefix/--suffix.
+    #[arg(long)]
+    patterns_file: Option<String>,
+
+    /// How patterns loaded from --patterns-file are anchored: "prefix",
+    /// "suffix", or "anywhere"
+    #[arg(long, default_value = "suffix")]
+    patterns_mode: String,
+
+    /// Match addresses against a regular expression, compiled once into a
+    /// DFA and shared across all search threads. Use `^`/`$` to anchor to
+    /// the start/end of the address. Takes priority over --patterns-file
+    /// and --prefix/--suffix when set.
+    #[arg(long)]
+    regex: Option<String>,
+
+    /// Keep a leaderboard of the N closest near-misses (scored against
+    /// --prefix/--suffix) and report them if --max-attempts is reached
+    /// without an exact match. 0 disables the leaderboard. Only valid
+    /// with the prefix/suffix matcher; rejected alongside --regex or
+    /// --patterns-file, since there's no prefix/suffix target to score
+    /// near-misses against under those matchers.
+    #[arg(long, default_value_t = 0)]
+    leaderboard: usize,
 }
 
 #[derive(Clone)]
@@ -165,6 +201,547 @@ impl OptimizedPattern {
     }
 }
 
+/// Interchangeable matching strategy for generated addresses.
+///
+/// The rayon search loop only ever talks to this trait, so new matching
+/// backends (regex, multi-pattern automata, fuzzy scoring, ...) can be
+/// dropped in without touching the driver.
+trait AddressMatcher: Send + Sync {
+    /// Returns true if `address` satisfies this matcher's criteria.
+    fn is_match(&self, address: &str) -> bool;
+
+    /// Human-readable summary of what this matcher is looking for, used in
+    /// startup logging.
+    fn describe(&self) -> String;
+
+    /// For matchers that track several concrete patterns (e.g. a
+    /// multi-pattern automaton), returns the specific pattern that matched
+    /// `address`. Matchers with a single fixed target can leave this at
+    /// the default.
+    fn matched_label(&self, _address: &str) -> Option<String> {
+        None
+    }
+}
+
+/// The original prefix/suffix/case-mode matching logic, now behind
+/// `AddressMatcher` instead of being inlined in `main`.
+struct PrefixSuffixMatcher {
+    prefix: Option<String>,
+    suffix: Option<String>,
+    optimized_prefix: Option<OptimizedPattern>,
+    optimized_suffix: Option<OptimizedPattern>,
+    case_sensitive: bool,
+}
+
+impl PrefixSuffixMatcher {
+    fn new(
+        prefix: Option<String>,
+        suffix: Option<String>,
+        case_mode: &str,
+        case_sensitive: bool,
+    ) -> Self {
+        let optimized_prefix = prefix
+            .as_deref()
+            .map(|p| OptimizedPattern::new(p, case_mode));
+        let optimized_suffix = suffix
+            .as_deref()
+            .map(|s| OptimizedPattern::new(s, case_mode));
+
+        Self {
+            prefix,
+            suffix,
+            optimized_prefix,
+            optimized_suffix,
+            case_sensitive,
+        }
+    }
+}
+
+impl AddressMatcher for PrefixSuffixMatcher {
+    #[inline(always)]
+    fn is_match(&self, address: &str) -> bool {
+        if self.case_sensitive {
+            let prefix_matches = match &self.prefix {
+                Some(prefix) => address.starts_with(prefix.as_str()),
+                None => true,
+            };
+
+            let suffix_matches = match &self.suffix {
+                Some(suffix) => address.ends_with(suffix.as_str()),
+                None => true,
+            };
+
+            prefix_matches && suffix_matches
+        } else {
+            let prefix_matches = match &self.optimized_prefix {
+                Some(opt_prefix) => opt_prefix.matches(address),
+                None => true,
+            };
+
+            let suffix_matches = match &self.optimized_suffix {
+                Some(opt_suffix) => opt_suffix.matches_suffix(address),
+                None => true,
+            };
+
+            prefix_matches && suffix_matches
+        }
+    }
+
+    fn describe(&self) -> String {
+        match (&self.prefix, &self.suffix) {
+            (Some(prefix), Some(suffix)) => {
+                format!("prefix \"{}\" and suffix \"{}\"", prefix, suffix)
+            }
+            (Some(prefix), None) => format!("prefix \"{}\"", prefix),
+            (None, Some(suffix)) => format!("suffix \"{}\"", suffix),
+            (None, None) => "any address".to_string(),
+        }
+    }
+}
+
+/// Where a pattern set loaded via --patterns-file is anchored when
+/// matching a generated address.
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum PatternAnchor {
+    Prefix,
+    Suffix,
+    Anywhere,
+}
+
+impl PatternAnchor {
+    fn parse(mode: &str) -> Result<Self, String> {
+        match mode {
+            "prefix" => Ok(PatternAnchor::Prefix),
+            "suffix" => Ok(PatternAnchor::Suffix),
+            "anywhere" => Ok(PatternAnchor::Anywhere),
+            other => Err(format!(
+                "unknown --patterns-mode \"{}\" (expected prefix, suffix, or anywhere)",
+                other
+            )),
+        }
+    }
+}
+
+/// A single node of the trie underlying `AhoCorasickMatcher`.
+struct TrieNode {
+    children: HashMap<u8, usize>,
+    /// Failure link used only in `Anywhere` mode.
+    fail: usize,
+    /// Index into `AhoCorasickMatcher::patterns` if a pattern terminates
+    /// here, including patterns inherited through failure links.
+    output: Option<usize>,
+}
+
+impl TrieNode {
+    fn new() -> Self {
+        Self {
+            children: HashMap::new(),
+            fail: 0,
+            output: None,
+        }
+    }
+}
+
+/// Matches a generated address against many target strings at once by
+/// walking a trie built over the pattern set, rather than comparing each
+/// pattern in turn.
+///
+/// `Prefix`/`Suffix` anchoring just walks the trie from the root one byte
+/// at a time and reports a hit the moment a terminal node is reached, so
+/// no failure links are needed. `Anywhere` matching follows classic
+/// Aho-Corasick failure links (the longest proper suffix of the current
+/// state that is also a trie prefix) computed by a BFS over the trie.
+struct AhoCorasickMatcher {
+    anchor: PatternAnchor,
+    case_sensitive: bool,
+    patterns: Vec<String>,
+    nodes: Vec<TrieNode>,
+}
+
+impl AhoCorasickMatcher {
+    fn new(patterns: Vec<String>, anchor: PatternAnchor, case_sensitive: bool) -> Self {
+        let mut nodes = vec![TrieNode::new()];
+
+        for (index, pattern) in patterns.iter().enumerate() {
+            let bytes: Vec<u8> = if anchor == PatternAnchor::Suffix {
+                pattern.bytes().rev().collect()
+            } else {
+                pattern.bytes().collect()
+            };
+
+            let mut node = 0usize;
+            for byte in bytes {
+                let key = if case_sensitive { byte } else { byte.to_ascii_lowercase() };
+                node = match nodes[node].children.get(&key) {
+                    Some(&next) => next,
+                    None => {
+                        nodes.push(TrieNode::new());
+                        let next = nodes.len() - 1;
+                        nodes[node].children.insert(key, next);
+                        next
+                    }
+                };
+            }
+            nodes[node].output = Some(index);
+        }
+
+        if anchor == PatternAnchor::Anywhere {
+            Self::build_failure_links(&mut nodes);
+        }
+
+        Self {
+            anchor,
+            case_sensitive,
+            patterns,
+            nodes,
+        }
+    }
+
+    /// Computes failure links and propagates output through them with a
+    /// BFS over the trie, so a node's `output` also covers any pattern
+    /// that ends at a state reachable by following failure links.
+    fn build_failure_links(nodes: &mut [TrieNode]) {
+        let mut queue = VecDeque::new();
+
+        let root_children: Vec<(u8, usize)> = nodes[0]
+            .children
+            .iter()
+            .map(|(&byte, &child)| (byte, child))
+            .collect();
+        for (_, child) in root_children {
+            nodes[child].fail = 0;
+            queue.push_back(child);
+        }
+
+        while let Some(node) = queue.pop_front() {
+            let children: Vec<(u8, usize)> = nodes[node]
+                .children
+                .iter()
+                .map(|(&byte, &child)| (byte, child))
+                .collect();
+
+            for (byte, child) in children {
+                let mut fallback = nodes[node].fail;
+                while fallback != 0 && !nodes[fallback].children.contains_key(&byte) {
+                    fallback = nodes[fallback].fail;
+                }
+                let fail = nodes[fallback]
+                    .children
+                    .get(&byte)
+                    .copied()
+                    .filter(|&candidate| candidate != child)
+                    .unwrap_or(0);
+
+                nodes[child].fail = fail;
+                if nodes[child].output.is_none() {
+                    nodes[child].output = nodes[fail].output;
+                }
+                queue.push_back(child);
+            }
+        }
+    }
+
+    fn fold(&self, byte: u8) -> u8 {
+        if self.case_sensitive {
+            byte
+        } else {
+            byte.to_ascii_lowercase()
+        }
+    }
+
+    /// Walks an anchored (prefix or suffix) pattern set from the root,
+    /// reporting a hit the moment a terminal node is reached.
+    fn match_anchored(&self, bytes: impl Iterator<Item = u8>) -> Option<usize> {
+        let mut node = 0usize;
+        for byte in bytes {
+            match self.nodes[node].children.get(&self.fold(byte)) {
+                Some(&next) => node = next,
+                None => return None,
+            }
+            if let Some(pattern_index) = self.nodes[node].output {
+                return Some(pattern_index);
+            }
+        }
+        None
+    }
+
+    /// Walks the address from the start, following failure links whenever
+    /// the current state has no matching child, to find any pattern
+    /// occurring anywhere in the address.
+    fn match_anywhere(&self, address: &str) -> Option<usize> {
+        let mut node = 0usize;
+        for byte in address.bytes() {
+            let byte = self.fold(byte);
+            while node != 0 && !self.nodes[node].children.contains_key(&byte) {
+                node = self.nodes[node].fail;
+            }
+            node = self.nodes[node].children.get(&byte).copied().unwrap_or(0);
+            if let Some(pattern_index) = self.nodes[node].output {
+                return Some(pattern_index);
+            }
+        }
+        None
+    }
+
+    fn find_match(&self, address: &str) -> Option<usize> {
+        match self.anchor {
+            PatternAnchor::Prefix => self.match_anchored(address.bytes()),
+            PatternAnchor::Suffix => self.match_anchored(address.bytes().rev()),
+            PatternAnchor::Anywhere => self.match_anywhere(address),
+        }
+    }
+}
+
+impl AddressMatcher for AhoCorasickMatcher {
+    fn is_match(&self, address: &str) -> bool {
+        self.find_match(address).is_some()
+    }
+
+    fn describe(&self) -> String {
+        let anchor = match self.anchor {
+            PatternAnchor::Prefix => "prefix",
+            PatternAnchor::Suffix => "suffix",
+            PatternAnchor::Anywhere => "anywhere",
+        };
+        format!("{} of {} patterns from --patterns-file", anchor, self.patterns.len())
+    }
+
+    fn matched_label(&self, address: &str) -> Option<String> {
+        self.find_match(address).map(|index| self.patterns[index].clone())
+    }
+}
+
+/// Loads newline-separated target patterns from `path`, skipping blank
+/// lines, for use with `AhoCorasickMatcher`.
+fn load_patterns_file(path: &str) -> Result<Vec<String>, std::io::Error> {
+    let content = fs::read_to_string(path)?;
+    let patterns: Vec<String> = content
+        .lines()
+        .map(|line| line.trim().to_string())
+        .filter(|line| !line.is_empty())
+        .collect();
+    Ok(patterns)
+}
+
+/// Rejects a regex pattern that references a literal character outside the
+/// base58 alphabet, since such a pattern can never match a Solana address
+/// and would otherwise run until --max-attempts with no chance of success.
+///
+/// This is a best-effort scan, not a full regex parse: it walks the raw
+/// pattern text, skipping escape sequences (`\d`, `\(`, ...) and `{m,n}`
+/// quantifiers, and flags any other alphanumeric literal not in
+/// `BASE58_ALPHABET`.
+fn validate_regex_alphabet(pattern: &str) -> Result<(), String> {
+    let mut chars = pattern.chars();
+
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' => {
+                chars.next();
+            }
+            '{' => {
+                for skipped in chars.by_ref() {
+                    if skipped == '}' {
+                        break;
+                    }
+                }
+            }
+            c if c.is_ascii_alphanumeric() && !BASE58_ALPHABET.contains(c) => {
+                return Err(format!(
+                    "regex references '{}', which cannot appear in a base58 address",
+                    c
+                ));
+            }
+            _ => {}
+        }
+    }
+
+    Ok(())
+}
+
+/// Matches a generated address against a user-supplied regex compiled once
+/// into a DFA at startup. The hot loop just feeds address bytes through
+/// the DFA's transition table and accepts as soon as a match state is
+/// reached, with no backtracking and no per-candidate allocation.
+struct RegexMatcher {
+    pattern: String,
+    dfa: dense::DFA<Vec<u32>>,
+}
+
+impl RegexMatcher {
+    fn new(pattern: &str) -> Result<Self, String> {
+        validate_regex_alphabet(pattern)?;
+
+        let dfa = dense::DFA::new(pattern)
+            .map_err(|e| format!("failed to compile --regex \"{}\": {}", pattern, e))?;
+
+        Ok(Self {
+            pattern: pattern.to_string(),
+            dfa,
+        })
+    }
+}
+
+impl AddressMatcher for RegexMatcher {
+    #[inline(always)]
+    fn is_match(&self, address: &str) -> bool {
+        matches!(self.dfa.try_search_fwd(&Input::new(address).earliest(true)), Ok(Some(_)))
+    }
+
+    fn describe(&self) -> String {
+        format!("regex /{}/ (DFA)", self.pattern)
+    }
+}
+
+/// How closely a candidate address agrees with a configured prefix/suffix
+/// target, used to rank near-misses for `--leaderboard`.
+///
+/// Ordered first by `run_len` (the length of the unbroken matching run
+/// from the anchor), then by `total_matches` (matching characters overall)
+/// as a tiebreaker, matching field declaration order.
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord)]
+struct MatchScore {
+    run_len: usize,
+    total_matches: usize,
+}
+
+/// Compares a single target character against a candidate character under
+/// the given `case_mode`, mirroring `OptimizedPattern`'s case handling.
+#[inline(always)]
+fn char_matches(case_mode: &str, pattern_byte: u8, text_byte: u8) -> bool {
+    match case_mode {
+        "exact" => pattern_byte == text_byte,
+        "upper" | "lower" => pattern_byte.eq_ignore_ascii_case(&text_byte),
+        "mixed" => {
+            if pattern_byte.is_ascii_uppercase() {
+                text_byte.is_ascii_uppercase()
+            } else if pattern_byte.is_ascii_lowercase() {
+                text_byte.is_ascii_lowercase()
+            } else {
+                // OptimizedPattern::matches_mixed_case imposes no
+                // constraint at non-letter positions; mirror that here.
+                true
+            }
+        }
+        _ => pattern_byte == text_byte,
+    }
+}
+
+/// Scans `text` against `pattern` one direction at a time, returning the
+/// length of the unbroken matching run from the start of the scan and the
+/// total number of matching positions overall.
+fn scan_match_run(text: &[u8], pattern: &[u8], case_mode: &str, reverse: bool) -> (usize, usize) {
+    let len = text.len().min(pattern.len());
+    let mut run_len = 0;
+    let mut run_broken = false;
+    let mut total_matches = 0;
+
+    for i in 0..len {
+        let (t, p) = if reverse {
+            (text[text.len() - 1 - i], pattern[pattern.len() - 1 - i])
+        } else {
+            (text[i], pattern[i])
+        };
+
+        if char_matches(case_mode, p, t) {
+            total_matches += 1;
+            if !run_broken {
+                run_len += 1;
+            }
+        } else {
+            run_broken = true;
+        }
+    }
+
+    (run_len, total_matches)
+}
+
+/// Scores `address` against the configured prefix (left-to-right) and
+/// suffix (right-to-left) targets for the `--leaderboard` near-miss mode.
+fn score_against(address: &str, prefix: Option<&str>, suffix: Option<&str>, case_mode: &str) -> MatchScore {
+    let address_bytes = address.as_bytes();
+    let mut run_len = 0;
+    let mut total_matches = 0;
+
+    if let Some(prefix) = prefix {
+        let (r, t) = scan_match_run(address_bytes, prefix.as_bytes(), case_mode, false);
+        run_len += r;
+        total_matches += t;
+    }
+
+    if let Some(suffix) = suffix {
+        let (r, t) = scan_match_run(address_bytes, suffix.as_bytes(), case_mode, true);
+        run_len += r;
+        total_matches += t;
+    }
+
+    MatchScore { run_len, total_matches }
+}
+
+/// A single leaderboard candidate: a near-miss address kept because it
+/// beat the worst entry currently on a worker's heap. `attempts`/`elapsed`
+/// capture the run's progress at the moment this candidate was recorded,
+/// so a saved entry reflects when it was actually seen rather than the
+/// totals at the end of the run.
+#[derive(Clone, PartialEq, Eq)]
+struct LeaderboardEntry {
+    score: MatchScore,
+    address: String,
+    private_key: String,
+    attempts: u64,
+    elapsed: std::time::Duration,
+}
+
+impl PartialOrd for LeaderboardEntry {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for LeaderboardEntry {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        self.score.cmp(&other.score)
+    }
+}
+
+/// What a single search worker produced when its loop ended: either an
+/// exact match, or the near-miss leaderboard it collected before stopping
+/// (at --max-attempts, or because a sibling worker found a match).
+enum WorkerOutcome {
+    Found,
+    Exhausted {
+        leaderboard: BinaryHeap<Reverse<LeaderboardEntry>>,
+    },
+}
+
+/// Bounds-checked prefix slice of a found `address` for post-match
+/// reporting/metadata, returning `None` instead of panicking if `prefix_len`
+/// exceeds the address length. `main` clears --prefix/--suffix for
+/// --patterns-file/--regex runs, and `PrefixSuffixMatcher` only matches
+/// addresses at least as long as its targets, so this is a defensive check.
+#[inline(always)]
+fn bounded_prefix_slice(address: &str, prefix_len: usize) -> Option<&str> {
+    address.get(..prefix_len)
+}
+
+/// Bounds-checked suffix slice, mirroring `bounded_prefix_slice` for
+/// --suffix.
+#[inline(always)]
+fn bounded_suffix_slice(address: &str, suffix_len: usize) -> Option<&str> {
+    address.len().checked_sub(suffix_len).and_then(|start| address.get(start..))
+}
+
+/// Whether a search worker should stop generating candidates because a
+/// sibling worker already found a match. Factored out of the worker loop
+/// so this check - dropped entirely by the initial `--leaderboard`
+/// implementation and restored in two follow-up fixes - has unit coverage
+/// instead of relying on a manual `cargo run` to notice an unbounded run
+/// hanging.
+#[inline(always)]
+fn stop_requested(stop: &AtomicBool) -> bool {
+    stop.load(Ordering::Relaxed)
+}
+
 fn analyze_case_pattern(pattern: &str) -> (bool, bool, bool) {
     let mut has_upper = false;
     let mut has_lower = false;
@@ -181,24 +758,40 @@ fn analyze_case_pattern(pattern: &str) -> (bool, bool, bool) {
     (has_upper, has_lower, has_mixed)
 }
 
-fn save_to_json(address: &str, private_key: &str, attempts: u64, elapsed_time: std::time::Duration, 
-                prefix: Option<&str>, suffix: &str, case_mode: &str, filename: &str) -> Result<(), Box<dyn std::error::Error>> {
+/// Per-find metadata for `save_to_json`, bundled into one struct so each
+/// new thing worth recording (matched pattern, leaderboard score, ...)
+/// doesn't grow `save_to_json`'s argument list.
+struct FindMetadata<'a> {
+    prefix: Option<&'a str>,
+    suffix: &'a str,
+    case_mode: &'a str,
+    matched_pattern: Option<&'a str>,
+    leaderboard_score: Option<(usize, usize)>,
+}
+
+fn save_to_json(address: &str, private_key: &str, attempts: u64, elapsed_time: std::time::Duration,
+                metadata: &FindMetadata, filename: &str) -> Result<(), Box<dyn std::error::Error>> {
     let now: DateTime<Utc> = Utc::now();
-    
+
     let new_address = json!({
         "address": address,
         "private_key": private_key,
         "found_at": now.to_rfc3339(),
         "search_parameters": {
-            "prefix": prefix,
-            "suffix": suffix,
-            "case_mode": case_mode
+            "prefix": metadata.prefix,
+            "suffix": metadata.suffix,
+            "case_mode": metadata.case_mode,
+            "matched_pattern": metadata.matched_pattern
         },
         "search_stats": {
             "attempts": attempts,
             "elapsed_time_seconds": elapsed_time.as_secs_f64(),
             "elapsed_time_human": format!("{:?}", elapsed_time)
-        }
+        },
+        "leaderboard_score": metadata.leaderboard_score.map(|(run_len, total_matches)| json!({
+            "run_len": run_len,
+            "total_matches": total_matches
+        }))
     });
     
     // Try to read existing file and append to it
@@ -250,14 +843,34 @@ fn display_current_addresses(filename: &str) -> Result<(), Box<dyn std::error::E
 }
 
 fn main() {
-    let args = Args::parse();
-    
+    let mut args = Args::parse();
+
     // Validate that at least one pattern is provided
-    if args.prefix.is_none() && args.suffix.is_empty() {
-        eprintln!("âŒ Error: At least one of --prefix or --suffix must be specified");
+    if args.prefix.is_none() && args.suffix.is_empty() && args.patterns_file.is_none() && args.regex.is_none() {
+        eprintln!("âŒ Error: At least one of --prefix, --suffix, --patterns-file, or --regex must be specified");
         std::process::exit(1);
     }
-    
+
+    // --leaderboard scores near-misses against --prefix/--suffix, so it's
+    // meaningless (and misleading) for the other matcher backends.
+    if args.leaderboard > 0 && (args.regex.is_some() || args.patterns_file.is_some()) {
+        eprintln!("âŒ Error: --leaderboard scores near-misses against --prefix/--suffix and can't be combined with --regex or --patterns-file");
+        std::process::exit(1);
+    }
+
+    // --suffix defaults to "pump" so plain prefix/suffix runs work out of
+    // the box, and --prefix is likewise only meaningful to
+    // PrefixSuffixMatcher, but neither is mutually exclusive with
+    // --patterns-file/--regex at the CLI level. Clear both here, before any
+    // of the prefix/suffix-keyed logging/metadata below runs, so those runs
+    // don't print or save a bogus match they never actually looked for (and
+    // don't slice a found address with a leftover --prefix/--suffix the
+    // active matcher never validated the length of).
+    if args.patterns_file.is_some() || args.regex.is_some() {
+        args.suffix = String::new();
+        args.prefix = None;
+    }
+
     // Analyze the case pattern of the patterns
     let suffix_analysis = if !args.suffix.is_empty() {
         Some(analyze_case_pattern(&args.suffix))
@@ -296,19 +909,45 @@ fn main() {
         println!("   - Mixed case: {}", has_mixed);
     }
     
-    // Pre-compute optimized patterns
-    let optimized_suffix = if !args.suffix.is_empty() {
-        Some(OptimizedPattern::new(&args.suffix, &args.case_mode))
-    } else {
-        None
-    };
-    
-    let optimized_prefix = if let Some(ref prefix) = args.prefix {
-        Some(OptimizedPattern::new(prefix, &args.case_mode))
+    // Build the matching strategy for this run. The search loop below only
+    // depends on the AddressMatcher trait, so the strategy is picked here
+    // without touching the loop itself. The matcher is shared across all
+    // rayon threads via Arc rather than rebuilt or cloned per-thread.
+    let matcher: Arc<dyn AddressMatcher> = if let Some(ref pattern) = args.regex {
+        let regex_matcher = RegexMatcher::new(pattern).unwrap_or_else(|e| {
+            eprintln!("âŒ Error: {}", e);
+            std::process::exit(1);
+        });
+        Arc::new(regex_matcher)
+    } else if let Some(ref patterns_file) = args.patterns_file {
+        let anchor = PatternAnchor::parse(&args.patterns_mode).unwrap_or_else(|e| {
+            eprintln!("âŒ Error: {}", e);
+            std::process::exit(1);
+        });
+        let patterns = load_patterns_file(patterns_file).unwrap_or_else(|e| {
+            eprintln!("âŒ Error: Could not read --patterns-file {}: {}", patterns_file, e);
+            std::process::exit(1);
+        });
+        if patterns.is_empty() {
+            eprintln!("âŒ Error: --patterns-file {} contained no patterns", patterns_file);
+            std::process::exit(1);
+        }
+        Arc::new(AhoCorasickMatcher::new(patterns, anchor, args.case_sensitive))
     } else {
-        None
+        let suffix_for_matcher = if !args.suffix.is_empty() {
+            Some(args.suffix.clone())
+        } else {
+            None
+        };
+        Arc::new(PrefixSuffixMatcher::new(
+            args.prefix.clone(),
+            suffix_for_matcher,
+            &args.case_mode,
+            args.case_sensitive,
+        ))
     };
-    
+    println!("ğŸ§© Matcher: {}", matcher.describe());
+
     // Clear output file if requested
     if args.clear_output {
         let empty_data = json!({ "vanity_addresses": [] });
@@ -329,12 +968,9 @@ fn main() {
     let num_threads = if args.threads == 0 {
         // Use optimal thread count based on CPU cores and pattern complexity
         let cpu_cores = num_cpus::get();
-        let pattern_complexity = match (&optimized_prefix, &optimized_suffix) {
-            (Some(p), Some(s)) => p.pattern_len + s.pattern_len,
-            (Some(p), None) => p.pattern_len,
-            (None, Some(s)) => s.pattern_len,
-            _ => 1,
-        };
+        let prefix_len = args.prefix.as_ref().map(|p| p.len()).unwrap_or(0);
+        let suffix_len = if args.suffix.is_empty() { 0 } else { args.suffix.len() };
+        let pattern_complexity = (prefix_len + suffix_len).max(1);
         
         // More complex patterns benefit from more threads
         let optimal_threads = if pattern_complexity > 8 {
@@ -364,125 +1000,209 @@ fn main() {
     let start_time = Instant::now();
     let attempts = Arc::new(AtomicU64::new(0));
     let found = Arc::new(AtomicU64::new(0));
-    
+    let stop = Arc::new(AtomicBool::new(false));
+
     // Create work chunks for better thread distribution
     let work_chunks: Vec<Vec<()>> = (0..num_threads)
         .map(|_| vec![(); args.chunk_size])
         .collect();
-    
-    // Search for vanity address using parallel iterator with chunked work
-    let result = work_chunks
+
+    let leaderboard_prefix = args.prefix.as_deref();
+    let leaderboard_suffix = if args.suffix.is_empty() { None } else { Some(args.suffix.as_str()) };
+
+    // --case-sensitive makes PrefixSuffixMatcher::is_match compare bytes
+    // exactly, ignoring --case-mode entirely (see its `case_sensitive`
+    // branch above). The leaderboard scorer must agree, or near-misses get
+    // ranked against a looser case rule than the one actually being
+    // searched for.
+    let leaderboard_case_mode: &str = if args.case_sensitive { "exact" } else { args.case_mode.as_str() };
+
+    // Search for vanity address using parallel iterator with chunked work.
+    // Every worker checks the shared `stop` flag each iteration and bails
+    // out the instant any worker finds a match, so the search still stops
+    // at the first hit like the old `find_any` driver did. Collecting every
+    // worker's outcome (instead of returning as soon as one is `true`) is
+    // what lets --leaderboard merge every worker's near-miss heap
+    // afterwards; the short-circuit is what keeps that collection cheap.
+    let outcomes: Vec<WorkerOutcome> = work_chunks
         .into_par_iter()
-        .find_any(|_| {
+        .map(|_| {
             let mut local_attempts = 0u64;
             let mut last_progress = 0u64;
-            
+            let mut leaderboard: BinaryHeap<Reverse<LeaderboardEntry>> = BinaryHeap::new();
+
             loop {
+                if stop_requested(&stop) {
+                    return WorkerOutcome::Exhausted { leaderboard };
+                }
+
                 local_attempts += 1;
-                
-                // Check if we've reached max attempts
+
+                // Check if we've reached max attempts. The periodic flush
+                // below already folds most of local_attempts into the
+                // global counter, so don't add it again here.
                 if args.max_attempts > 0 && local_attempts >= args.max_attempts {
-                    return true;
+                    return WorkerOutcome::Exhausted { leaderboard };
                 }
-                
+
                 // Generate a new keypair
                 let keypair = Keypair::new();
                 let address = keypair.pubkey().to_string();
-                
-                // Check if address matches both prefix and suffix patterns
-                let matches = if args.case_sensitive {
-                    let prefix_matches = if let Some(ref prefix) = args.prefix {
-                        address.starts_with(prefix)
-                    } else {
-                        true
-                    };
-                    
-                    let suffix_matches = if !args.suffix.is_empty() {
-                        address.ends_with(&args.suffix)
-                    } else {
-                        true
-                    };
-                    
-                    prefix_matches && suffix_matches
-                } else {
-                    let prefix_matches = if let Some(ref opt_prefix) = optimized_prefix {
-                        opt_prefix.matches(&address)
-                    } else {
-                        true
-                    };
-                    
-                    let suffix_matches = if let Some(ref opt_suffix) = optimized_suffix {
-                        opt_suffix.matches_suffix(&address)
-                    } else {
-                        true
-                    };
-                    
-                    prefix_matches && suffix_matches
-                };
-                
+
+                // Delegate the matching decision to whichever AddressMatcher
+                // was selected at startup.
+                let matches = matcher.is_match(&address);
+
                 if matches {
+                    // Signal every other worker to stop before doing any of
+                    // the (comparatively slow) reporting/saving below, so
+                    // they bail out as close to this instant as possible.
+                    stop.store(true, Ordering::Relaxed);
+
                     let total_attempts = attempts.fetch_add(local_attempts, Ordering::Relaxed) + local_attempts;
                     found.store(total_attempts, Ordering::Relaxed);
-                    
+
                     println!("ğŸ‰ Found matching address!");
                     println!("ğŸ“ Address: {}", address);
                     println!("ğŸ”‘ Private key: [{}]", keypair.to_base58_string());
                     println!("ğŸ“Š Attempts: {}", total_attempts);
                     println!("â±ï¸  Time taken: {:?}", start_time.elapsed());
-                    
-                    // Show pattern analysis of the found address
+
+                    let matched_pattern = matcher.matched_label(&address);
+                    if let Some(ref pattern) = matched_pattern {
+                        println!("ğŸ”— Matched pattern: {}", pattern);
+                    }
+
+                    // Show pattern analysis of the found address. Bounds-checked
+                    // via bounded_prefix_slice/bounded_suffix_slice rather than
+                    // direct slicing: args.prefix/suffix are cleared above for
+                    // the other matchers, and PrefixSuffixMatcher never matches
+                    // an address shorter than its targets, so a slice that
+                    // would go out of bounds is skipped rather than panicking.
                     if let Some(ref prefix) = args.prefix {
-                        let found_prefix = &address[..prefix.len()];
-                        let (found_upper, found_lower, found_mixed) = analyze_case_pattern(found_prefix);
-                        println!("ğŸ” Found prefix analysis:");
-                        println!("   - Found prefix: {}", found_prefix);
-                        println!("   - Contains uppercase: {}", found_upper);
-                        println!("   - Contains lowercase: {}", found_lower);
-                        println!("   - Mixed case: {}", found_mixed);
+                        if let Some(found_prefix) = bounded_prefix_slice(&address, prefix.len()) {
+                            let (found_upper, found_lower, found_mixed) = analyze_case_pattern(found_prefix);
+                            println!("ğŸ” Found prefix analysis:");
+                            println!("   - Found prefix: {}", found_prefix);
+                            println!("   - Contains uppercase: {}", found_upper);
+                            println!("   - Contains lowercase: {}", found_lower);
+                            println!("   - Mixed case: {}", found_mixed);
+                        }
                     }
-                    
+
                     if !args.suffix.is_empty() {
-                        let found_suffix = &address[address.len() - args.suffix.len()..];
-                        let (found_upper, found_lower, found_mixed) = analyze_case_pattern(found_suffix);
-                        println!("ğŸ” Found suffix analysis:");
-                        println!("   - Found suffix: {}", found_suffix);
-                        println!("   - Contains uppercase: {}", found_upper);
-                        println!("   - Contains lowercase: {}", found_lower);
-                        println!("   - Mixed case: {}", found_mixed);
+                        if let Some(found_suffix) = bounded_suffix_slice(&address, args.suffix.len()) {
+                            let (found_upper, found_lower, found_mixed) = analyze_case_pattern(found_suffix);
+                            println!("ğŸ” Found suffix analysis:");
+                            println!("   - Found suffix: {}", found_suffix);
+                            println!("   - Contains uppercase: {}", found_upper);
+                            println!("   - Contains lowercase: {}", found_lower);
+                            println!("   - Mixed case: {}", found_mixed);
+                        }
                     }
-                    
+
                     // Save data to JSON
                     let elapsed_time = start_time.elapsed();
-                    save_to_json(&address, &keypair.to_base58_string(), total_attempts, elapsed_time, 
-                                args.prefix.as_deref(), &args.suffix, &args.case_mode, &args.output).unwrap();
-                    
-                    return true;
+                    let metadata = FindMetadata {
+                        prefix: args.prefix.as_deref(),
+                        suffix: &args.suffix,
+                        case_mode: &args.case_mode,
+                        matched_pattern: matched_pattern.as_deref(),
+                        leaderboard_score: None,
+                    };
+                    save_to_json(&address, &keypair.to_base58_string(), total_attempts, elapsed_time,
+                                &metadata, &args.output).unwrap();
+
+                    return WorkerOutcome::Found;
                 }
-                
+
+                // Track the closest near-misses seen so a bounded run that
+                // never matches can still report something useful. Scoring
+                // every candidate is required, but the (allocating) heap
+                // update only runs while the heap still has room or when the
+                // candidate beats the worker's current worst entry.
+                if args.leaderboard > 0 {
+                    let score = score_against(&address, leaderboard_prefix, leaderboard_suffix, leaderboard_case_mode);
+                    let has_room = leaderboard.len() < args.leaderboard;
+                    let beats_worst = match leaderboard.peek() {
+                        Some(Reverse(worst)) => score > worst.score,
+                        None => true,
+                    };
+                    if has_room || beats_worst {
+                        if !has_room {
+                            leaderboard.pop();
+                        }
+                        leaderboard.push(Reverse(LeaderboardEntry {
+                            score,
+                            address: address.clone(),
+                            private_key: keypair.to_base58_string(),
+                            // Every full 50_000 of local_attempts is already
+                            // in the global counter; add only the unflushed rest.
+                            attempts: attempts.load(Ordering::Relaxed) + local_attempts % 50_000,
+                            elapsed: start_time.elapsed(),
+                        }));
+                    }
+                }
+
                 // Update global counter and progress less frequently for better performance
                 if local_attempts % 50_000 == 0 {
                     attempts.fetch_add(50_000, Ordering::Relaxed);
-                    
+
                     // Print progress every 5M attempts (reduced frequency for better performance)
                     let current_total = attempts.load(Ordering::Relaxed);
                     if current_total - last_progress >= 5_000_000 {
                         let elapsed = start_time.elapsed();
                         let rate = current_total as f64 / elapsed.as_secs_f64();
-                        println!("ğŸ” Attempts: {} | Rate: {:.0}/sec | Current: {}", 
+                        println!("ğŸ” Attempts: {} | Rate: {:.0}/sec | Current: {}",
                                 current_total, rate, address);
                         last_progress = current_total;
                     }
                 }
             }
-        });
-    
-    if result.is_some() {
+        })
+        .collect();
+
+    let found_match = outcomes.iter().any(|outcome| matches!(outcome, WorkerOutcome::Found));
+
+    if found_match {
         println!("\nâœ… Vanity address found successfully!");
     } else {
         println!("\nâŒ Search completed without finding a match");
         if args.max_attempts > 0 {
             println!("ğŸ“Š Total attempts: {}", attempts.load(Ordering::Relaxed));
         }
+
+        if args.leaderboard > 0 {
+            // Merging per-worker leaderboards is a one-shot sort, not a
+            // repeated extract-min, so a plain Vec is all that's needed -
+            // no reason to re-heapify the merged set.
+            let mut ranked: Vec<LeaderboardEntry> = outcomes
+                .into_iter()
+                .filter_map(|outcome| match outcome {
+                    WorkerOutcome::Exhausted { leaderboard } => Some(leaderboard),
+                    WorkerOutcome::Found => None,
+                })
+                .flatten()
+                .map(|Reverse(entry)| entry)
+                .collect();
+            ranked.sort_by(|a, b| b.score.cmp(&a.score));
+            ranked.truncate(args.leaderboard);
+
+            println!("\nğŸ† Closest near-misses (top {}):", ranked.len());
+            for (rank, entry) in ranked.iter().enumerate() {
+                println!("   {}. {} (run: {}, total matches: {})",
+                        rank + 1, entry.address, entry.score.run_len, entry.score.total_matches);
+                let metadata = FindMetadata {
+                    prefix: args.prefix.as_deref(),
+                    suffix: &args.suffix,
+                    case_mode: &args.case_mode,
+                    matched_pattern: None,
+                    leaderboard_score: Some((entry.score.run_len, entry.score.total_matches)),
+                };
+                save_to_json(&entry.address, &entry.private_key, entry.attempts, entry.elapsed,
+                            &metadata, &args.output).unwrap();
+            }
+        }
     }
     
     let total_time = start_time.elapsed();
@@ -494,3 +1214,172 @@ fn main() {
     println!("ğŸš€ Final rate: {:.0} attempts/second", final_rate);
     println!("ğŸ’¡ Performance tip: Adjust --chunk-size and --threads for optimal performance");
 }
+
+#[cfg(test)]
+mod bounded_slice_tests {
+    use super::*;
+
+    // Regression test for the crash reachable via `--patterns-file ...
+    // --prefix <70-char string>`: the Aho-Corasick matcher can find a match
+    // that has nothing to do with --prefix, and a leftover --prefix longer
+    // than the address used to panic post-match reporting with a direct
+    // `&address[..prefix.len()]` slice.
+    #[test]
+    fn bounded_prefix_slice_is_none_when_prefix_longer_than_address() {
+        let address = "GTaPmN9YGy4";
+        assert_eq!(bounded_prefix_slice(address, address.len() + 1), None);
+        assert_eq!(bounded_prefix_slice(address, 3), Some("GTa"));
+    }
+
+    #[test]
+    fn bounded_suffix_slice_is_none_when_suffix_longer_than_address() {
+        let address = "GTaPmN9YGy4";
+        assert_eq!(bounded_suffix_slice(address, address.len() + 1), None);
+        assert_eq!(bounded_suffix_slice(address, 3), Some("Gy4"));
+    }
+}
+
+#[cfg(test)]
+mod worker_stop_tests {
+    use super::*;
+    use std::sync::mpsc;
+    use std::thread;
+    use std::time::Duration;
+
+    // Regression test for the hang fixed in 83c4ff7/fd5dfa1: an unbounded
+    // worker's `loop` (--max-attempts 0) must notice a sibling worker's
+    // `stop.store(true, ...)` and return, rather than spinning forever.
+    // Runs against a real AtomicBool shared across threads, with a bounded
+    // `recv_timeout` so a reintroduced regression fails the test instead of
+    // hanging the suite.
+    #[test]
+    fn worker_stops_once_a_sibling_sets_the_flag() {
+        let stop = Arc::new(AtomicBool::new(false));
+        let worker_stop = Arc::clone(&stop);
+
+        let (tx, rx) = mpsc::channel();
+        thread::spawn(move || {
+            let mut iterations = 0u64;
+            while !stop_requested(&worker_stop) {
+                iterations += 1;
+            }
+            let _ = tx.send(iterations);
+        });
+
+        thread::sleep(Duration::from_millis(20));
+        stop.store(true, Ordering::Relaxed);
+
+        rx.recv_timeout(Duration::from_secs(2))
+            .expect("worker did not stop after the shared flag was set");
+    }
+}
+
+#[cfg(test)]
+mod leaderboard_scoring_tests {
+    use super::*;
+
+    #[test]
+    fn scan_match_run_tracks_run_and_total_separately() {
+        // "abXde" vs "abcde": run breaks at index 2, but "d" and "e" still
+        // count toward total_matches even though the run already broke.
+        let (run_len, total_matches) =
+            scan_match_run(b"abXde", b"abcde", "exact", false);
+        assert_eq!(run_len, 2);
+        assert_eq!(total_matches, 4);
+    }
+
+    #[test]
+    fn match_score_orders_by_run_len_then_total_matches() {
+        let better_run = MatchScore { run_len: 3, total_matches: 3 };
+        let worse_run_more_total = MatchScore { run_len: 2, total_matches: 5 };
+        let same_run_more_total = MatchScore { run_len: 3, total_matches: 4 };
+
+        assert!(better_run > worse_run_more_total);
+        assert!(same_run_more_total > better_run);
+    }
+
+    #[test]
+    fn score_against_upper_mode_ignores_ascii_case() {
+        let score = score_against("abcxyz", Some("ABC"), None, "upper");
+        assert_eq!(score.run_len, 3);
+    }
+
+    #[test]
+    fn case_sensitive_runs_must_use_exact_scoring_not_case_mode() {
+        // Mirrors the fix in main(): --case-sensitive forces byte-exact
+        // matching regardless of --case-mode, so scoring must use "exact"
+        // too or a near-miss ranking would reflect a looser rule than what
+        // was actually searched for.
+        let loose = score_against("abcxyz", Some("ABC"), None, "upper");
+        let strict = score_against("abcxyz", Some("ABC"), None, "exact");
+
+        assert_eq!(loose.run_len, 3);
+        assert_eq!(strict.run_len, 0);
+    }
+}
+
+#[cfg(test)]
+mod aho_corasick_tests {
+    use super::*;
+
+    #[test]
+    fn prefix_anchor_hits_and_misses() {
+        let matcher = AhoCorasickMatcher::new(
+            vec!["Abc".to_string(), "xyz".to_string()],
+            PatternAnchor::Prefix,
+            false,
+        );
+
+        assert!(matcher.is_match("abcDEF"));
+        assert_eq!(matcher.matched_label("abcDEF"), Some("Abc".to_string()));
+        assert!(matcher.is_match("XYZ123"));
+        assert!(!matcher.is_match("defabc"));
+    }
+
+    #[test]
+    fn suffix_anchor_hits_and_misses() {
+        let matcher = AhoCorasickMatcher::new(
+            vec!["pump".to_string(), "moon".to_string()],
+            PatternAnchor::Suffix,
+            false,
+        );
+
+        assert!(matcher.is_match("xyzPUMP"));
+        assert_eq!(matcher.matched_label("xyzPUMP"), Some("pump".to_string()));
+        assert!(matcher.is_match("xyzmoon"));
+        assert!(!matcher.is_match("pumpxyz"));
+    }
+
+    #[test]
+    fn anywhere_anchor_matches_overlapping_patterns() {
+        // "she", "he" and "hers" overlap: "ushers" is matched via "she"
+        // after starting mid-address, "xxheyy" via "he" after unmatched
+        // bytes, and an address containing none of them is rejected.
+        let matcher = AhoCorasickMatcher::new(
+            vec!["she".to_string(), "he".to_string(), "hers".to_string()],
+            PatternAnchor::Anywhere,
+            false,
+        );
+
+        assert!(matcher.is_match("ushers"));
+        assert!(matcher.is_match("xxheyy"));
+        assert!(!matcher.is_match("xxxxxx"));
+    }
+
+    #[test]
+    fn case_insensitive_folds_before_matching() {
+        let matcher = AhoCorasickMatcher::new(
+            vec!["PUMP".to_string()],
+            PatternAnchor::Suffix,
+            false,
+        );
+        assert!(matcher.is_match("abcpump"));
+
+        let case_sensitive = AhoCorasickMatcher::new(
+            vec!["PUMP".to_string()],
+            PatternAnchor::Suffix,
+            true,
+        );
+        assert!(!case_sensitive.is_match("abcpump"));
+    }
+}